@@ -0,0 +1,145 @@
+use super::id::{dlc_to_len, len_to_dlc, Id, IdReg};
+
+/// Maximum number of data bytes carried by a CAN FD frame.
+const MAX_DATA_LEN: usize = 64;
+
+/// A CAN FD data or remote frame, as held in a FlexCAN message-RAM mailbox.
+///
+/// Unlike [`Frame`](super::Frame), this carries the FDF bit set and can hold
+/// up to 64 data bytes, addressed through the extended DLC steps (see
+/// [`dlc_to_len`]/[`len_to_dlc`]).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "unstable-defmt", derive(defmt::Format))]
+pub struct FdFrame {
+    id: IdReg,
+    data: [u8; MAX_DATA_LEN],
+    len: u8,
+}
+
+impl FdFrame {
+    /// Creates a new FD data frame carrying `data`.
+    ///
+    /// `data.len()` must be one of the valid DLC steps (`0..=8, 12, 16, 20,
+    /// 24, 32, 48, 64`); any other length returns `None`.
+    pub fn new_data(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        let dlc = len_to_dlc(data.len())?;
+        let mut buf = [0; MAX_DATA_LEN];
+        buf[..data.len()].copy_from_slice(data);
+        Some(Self {
+            id: Self::id_reg(id.into()).with_dlc(dlc).with_fdf(true),
+            data: buf,
+            len: data.len() as u8,
+        })
+    }
+
+    /// Creates a new FD remote frame requesting `len` bytes of data.
+    ///
+    /// `len` must be one of the valid DLC steps (`0..=8, 12, 16, 20, 24, 32,
+    /// 48, 64`); any other length returns `None`.
+    pub fn new_remote(id: impl Into<Id>, len: usize) -> Option<Self> {
+        let dlc = len_to_dlc(len)?;
+        Some(Self {
+            id: Self::id_reg(id.into())
+                .with_dlc(dlc)
+                .with_fdf(true)
+                .with_rtr(true),
+            data: [0; MAX_DATA_LEN],
+            len: 0,
+        })
+    }
+
+    fn id_reg(id: Id) -> IdReg {
+        match id {
+            Id::Standard(id) => IdReg::new_standard(id),
+            Id::Extended(id) => IdReg::new_extended(id),
+        }
+    }
+
+    /// Requests a higher bit rate for this frame's data phase (sets BRS).
+    #[must_use = "returns a new FdFrame without modifying `self`"]
+    pub fn with_bit_rate_switching(mut self, brs: bool) -> Self {
+        self.id = self.id.with_brs(brs);
+        self
+    }
+
+    /// Returns the identifier of this frame.
+    pub fn id(&self) -> Id {
+        self.id.to_id()
+    }
+
+    /// Returns `true` if this frame carries an extended (29-bit) identifier.
+    pub fn is_extended(&self) -> bool {
+        self.id.is_extended()
+    }
+
+    /// Returns `true` if this frame carries a standard (11-bit) identifier.
+    pub fn is_standard(&self) -> bool {
+        self.id.is_standard()
+    }
+
+    /// Returns `true` if this is a remote frame.
+    pub fn is_remote_frame(&self) -> bool {
+        self.id.rtr()
+    }
+
+    /// Returns `true` if this is a data frame.
+    pub fn is_data_frame(&self) -> bool {
+        !self.is_remote_frame()
+    }
+
+    /// Returns `true` if this frame switches to a higher bit rate for its
+    /// data phase (BRS bit set).
+    pub fn bit_rate_switching(&self) -> bool {
+        self.id.brs()
+    }
+
+    /// Returns the number of data bytes carried by this frame.
+    ///
+    /// This is always `0` for remote frames, which carry no payload (the
+    /// requested length is only encoded in the DLC, not stored here).
+    pub fn len(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns `true` if this frame carries no data.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the data held by this frame.
+    ///
+    /// This is always empty for remote frames.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+}
+
+impl embedded_can::Frame for FdFrame {
+    fn new(id: impl Into<embedded_can::Id>, data: &[u8]) -> Option<Self> {
+        Self::new_data(Id::from(id.into()), data)
+    }
+
+    fn new_remote(id: impl Into<embedded_can::Id>, dlc: usize) -> Option<Self> {
+        Self::new_remote(Id::from(id.into()), dlc)
+    }
+
+    fn is_extended(&self) -> bool {
+        FdFrame::is_extended(self)
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        FdFrame::is_remote_frame(self)
+    }
+
+    fn id(&self) -> embedded_can::Id {
+        FdFrame::id(self).into()
+    }
+
+    fn dlc(&self) -> usize {
+        dlc_to_len(self.id.dlc())
+    }
+
+    fn data(&self) -> &[u8] {
+        FdFrame::data(self)
+    }
+}
@@ -0,0 +1,264 @@
+use super::id::{ExtendedId, Id, StandardId, STANDARD_ID_SHIFT};
+
+/// Where a message accepted by a [`Filter`] should be delivered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "unstable-defmt", derive(defmt::Format))]
+pub enum FilterAction {
+    /// Store the message in the given receive FIFO.
+    Fifo(u8),
+    /// Store the message in the given mailbox.
+    Mailbox(u8),
+    /// Discard the message; it is not stored anywhere.
+    Reject,
+}
+
+/// A hardware acceptance filter for the FlexCAN receive message RAM.
+///
+/// Matches either a single [`Id`] exactly ([`Filter::new`]), or a range of
+/// identifiers given by an `(Id, mask)` pair ([`Filter::new_mask`]), where a
+/// `0` mask bit means "don't care." Standard and extended filters are
+/// distinguished by the identifier's own variant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "unstable-defmt", derive(defmt::Format))]
+pub struct Filter {
+    id: Id,
+    mask: u32,
+    action: FilterAction,
+}
+
+impl Filter {
+    /// Creates a filter that matches `id` exactly.
+    pub fn new(id: impl Into<Id>, action: FilterAction) -> Self {
+        let id = id.into();
+        Self {
+            mask: Self::full_mask(id),
+            id,
+            action,
+        }
+    }
+
+    /// Creates a filter that matches any identifier `candidate` for which
+    /// `candidate.as_raw() & mask == id.as_raw() & mask`.
+    ///
+    /// `mask` is interpreted over the same raw range as `id`: 11 bits for a
+    /// [`StandardId`], 29 bits for an [`ExtendedId`]. Bits outside that
+    /// range are ignored.
+    pub fn new_mask(id: impl Into<Id>, mask: u32, action: FilterAction) -> Self {
+        let id = id.into();
+        Self {
+            mask: mask & Self::full_mask(id),
+            id,
+            action,
+        }
+    }
+
+    fn full_mask(id: Id) -> u32 {
+        match id {
+            Id::Standard(_) => u32::from(StandardId::MAX.as_raw()),
+            Id::Extended(_) => ExtendedId::MAX.as_raw(),
+        }
+    }
+
+    /// Returns `true` if this filter only matches standard (11-bit)
+    /// identifiers.
+    pub fn is_standard(&self) -> bool {
+        matches!(self.id, Id::Standard(_))
+    }
+
+    /// Returns `true` if this filter only matches extended (29-bit)
+    /// identifiers.
+    pub fn is_extended(&self) -> bool {
+        matches!(self.id, Id::Extended(_))
+    }
+
+    /// Returns the destination FIFO/mailbox/reject action for messages this
+    /// filter accepts.
+    pub fn action(&self) -> FilterAction {
+        self.action
+    }
+
+    /// Returns the raw mailbox ID register value and RXIMR mask register
+    /// value for this filter, ready to be written into the FlexCAN message
+    /// RAM by the CAN peripheral driver.
+    ///
+    /// This reuses the `StandardId`/`ExtendedId` raw values directly (only
+    /// `IdReg`'s priority `Ord` needs the RTR/IDE bookkeeping that transmit
+    /// arbitration cares about), but a standard identifier still has to sit
+    /// at the same `STANDARD_ID_SHIFT` bit offset within the ID word that
+    /// `IdReg::new_standard` uses, or it will never match an incoming
+    /// mailbox ID register. Extended identifiers occupy the full word, so
+    /// they're returned unshifted.
+    pub fn to_raw(&self) -> (u32, u32) {
+        match self.id {
+            Id::Standard(_) => (
+                self.id.as_raw() << STANDARD_ID_SHIFT,
+                self.mask << STANDARD_ID_SHIFT,
+            ),
+            Id::Extended(_) => (self.id.as_raw(), self.mask),
+        }
+    }
+}
+
+/// Register-level access needed to install acceptance filters into a
+/// FlexCAN instance's receive message RAM.
+///
+/// A concrete `Can` peripheral driver (not yet implemented in this crate)
+/// would implement this trait over its mailbox and RXIMR registers; keeping
+/// the trait separate lets [`install_filters`] be written and reasoned
+/// about without that peripheral existing yet.
+pub trait FilterRegisters {
+    /// Returns the number of hardware filter banks (mailboxes) available.
+    fn filter_count(&self) -> usize;
+
+    /// Writes `id`, the raw value returned by [`Filter::to_raw`], into
+    /// mailbox `index`'s ID register, configuring it as standard or
+    /// extended per `extended`.
+    fn set_mailbox_id(&mut self, index: usize, id: u32, extended: bool);
+
+    /// Writes `mask`, the raw value returned by [`Filter::to_raw`], into
+    /// mailbox `index`'s RXIMR register.
+    fn set_rximr(&mut self, index: usize, mask: u32);
+
+    /// Configures mailbox `index`'s destination per `action`: the FIFO or
+    /// mailbox a matching message should be stored in, or that it should be
+    /// rejected (left out of the mailbox's matching, or disabled) instead of
+    /// accepted.
+    fn set_action(&mut self, index: usize, action: FilterAction);
+}
+
+/// Returned by [`install_filters`] when there are more filters than
+/// hardware filter banks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TooManyFilters {
+    /// The number of hardware filter banks `regs` reported.
+    pub available: usize,
+    /// The number of filters that were requested.
+    pub requested: usize,
+}
+
+/// Installs `filters` into `regs`, programming each filter's mailbox ID
+/// register, RXIMR mask register, and destination action in order.
+///
+/// Returns `Err` without writing anything if `filters.len()` exceeds the
+/// number of hardware filter banks `regs` reports.
+pub fn install_filters(
+    regs: &mut impl FilterRegisters,
+    filters: &[Filter],
+) -> Result<(), TooManyFilters> {
+    let available = regs.filter_count();
+    if filters.len() > available {
+        return Err(TooManyFilters {
+            available,
+            requested: filters.len(),
+        });
+    }
+    for (index, filter) in filters.iter().enumerate() {
+        let (id, mask) = filter.to_raw();
+        regs.set_mailbox_id(index, id, filter.is_extended());
+        regs.set_rximr(index, mask);
+        regs.set_action(index, filter.action());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_raw_shifts_standard_ids_into_the_id_field() {
+        let id = StandardId::new(0x123).unwrap();
+        let filter = Filter::new(id, FilterAction::Reject);
+        let (raw_id, raw_mask) = filter.to_raw();
+        assert_eq!(raw_id, 0x123 << STANDARD_ID_SHIFT);
+        // An exact-match filter's mask covers every standard ID bit.
+        assert_eq!(raw_mask, u32::from(StandardId::MAX_RAW) << STANDARD_ID_SHIFT);
+    }
+
+    #[test]
+    fn to_raw_leaves_extended_ids_unshifted() {
+        let id = ExtendedId::new(0x1234).unwrap();
+        let filter = Filter::new(id, FilterAction::Reject);
+        let (raw_id, raw_mask) = filter.to_raw();
+        assert_eq!(raw_id, 0x1234);
+        assert_eq!(raw_mask, ExtendedId::MAX_RAW);
+    }
+
+    #[test]
+    fn to_raw_shifts_standard_mask_filters_too() {
+        let id = StandardId::new(0x100).unwrap();
+        let filter = Filter::new_mask(id, 0x700, FilterAction::Fifo(0));
+        let (raw_id, raw_mask) = filter.to_raw();
+        assert_eq!(raw_id, 0x100 << STANDARD_ID_SHIFT);
+        assert_eq!(raw_mask, 0x700 << STANDARD_ID_SHIFT);
+    }
+
+    #[derive(Default)]
+    struct MockRegisters {
+        banks: usize,
+        ids: Vec<(u32, bool)>,
+        masks: Vec<u32>,
+        actions: Vec<FilterAction>,
+    }
+
+    impl FilterRegisters for MockRegisters {
+        fn filter_count(&self) -> usize {
+            self.banks
+        }
+
+        fn set_mailbox_id(&mut self, index: usize, id: u32, extended: bool) {
+            self.ids.resize(self.ids.len().max(index + 1), (0, false));
+            self.ids[index] = (id, extended);
+        }
+
+        fn set_rximr(&mut self, index: usize, mask: u32) {
+            self.masks.resize(self.masks.len().max(index + 1), 0);
+            self.masks[index] = mask;
+        }
+
+        fn set_action(&mut self, index: usize, action: FilterAction) {
+            self.actions
+                .resize(self.actions.len().max(index + 1), FilterAction::Reject);
+            self.actions[index] = action;
+        }
+    }
+
+    #[test]
+    fn install_filters_programs_id_mask_and_action_per_bank() {
+        let mut regs = MockRegisters {
+            banks: 2,
+            ..Default::default()
+        };
+        let filters = [
+            Filter::new(StandardId::new(0x10).unwrap(), FilterAction::Fifo(3)),
+            Filter::new(ExtendedId::new(0x20).unwrap(), FilterAction::Reject),
+        ];
+        install_filters(&mut regs, &filters).unwrap();
+
+        assert_eq!(regs.ids[0], (0x10 << STANDARD_ID_SHIFT, false));
+        assert_eq!(regs.ids[1], (0x20, true));
+        assert_eq!(regs.actions[0], FilterAction::Fifo(3));
+        assert_eq!(regs.actions[1], FilterAction::Reject);
+    }
+
+    #[test]
+    fn install_filters_rejects_more_filters_than_banks() {
+        let mut regs = MockRegisters {
+            banks: 1,
+            ..Default::default()
+        };
+        let filters = [
+            Filter::new(StandardId::new(0x10).unwrap(), FilterAction::Reject),
+            Filter::new(StandardId::new(0x11).unwrap(), FilterAction::Reject),
+        ];
+        let err = install_filters(&mut regs, &filters).unwrap_err();
+        assert_eq!(
+            err,
+            TooManyFilters {
+                available: 1,
+                requested: 2,
+            }
+        );
+        assert!(regs.ids.is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use super::id::{Id, IdReg};
+
+/// Maximum number of data bytes carried by a classic CAN frame.
+const MAX_DATA_LEN: usize = 8;
+
+/// A classic CAN data or remote frame, as held in a FlexCAN mailbox.
+///
+/// The identifier, DLC, and RTR flag are packed into an [`IdReg`], matching
+/// the layout FlexCAN mailboxes use natively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "unstable-defmt", derive(defmt::Format))]
+pub struct Frame {
+    id: IdReg,
+    data: [u8; MAX_DATA_LEN],
+    len: u8,
+}
+
+impl Frame {
+    /// Creates a new data frame carrying `data`.
+    ///
+    /// Returns `None` if `data` holds more than 8 bytes.
+    pub fn new_data(id: impl Into<Id>, data: &[u8]) -> Option<Self> {
+        if data.len() > MAX_DATA_LEN {
+            return None;
+        }
+        let mut buf = [0; MAX_DATA_LEN];
+        buf[..data.len()].copy_from_slice(data);
+        Some(Self {
+            id: Self::id_reg(id.into()).with_dlc(data.len() as u8),
+            data: buf,
+            len: data.len() as u8,
+        })
+    }
+
+    /// Creates a new remote frame requesting `dlc` bytes of data.
+    ///
+    /// Returns `None` if `dlc` is greater than 8.
+    pub fn new_remote(id: impl Into<Id>, dlc: usize) -> Option<Self> {
+        if dlc > MAX_DATA_LEN {
+            return None;
+        }
+        Some(Self {
+            id: Self::id_reg(id.into()).with_dlc(dlc as u8).with_rtr(true),
+            data: [0; MAX_DATA_LEN],
+            len: 0,
+        })
+    }
+
+    fn id_reg(id: Id) -> IdReg {
+        match id {
+            Id::Standard(id) => IdReg::new_standard(id),
+            Id::Extended(id) => IdReg::new_extended(id),
+        }
+    }
+
+    /// Returns the identifier of this frame.
+    pub fn id(&self) -> Id {
+        self.id.to_id()
+    }
+
+    /// Returns `true` if this frame carries an extended (29-bit) identifier.
+    pub fn is_extended(&self) -> bool {
+        self.id.is_extended()
+    }
+
+    /// Returns `true` if this frame carries a standard (11-bit) identifier.
+    pub fn is_standard(&self) -> bool {
+        self.id.is_standard()
+    }
+
+    /// Returns `true` if this is a remote frame.
+    pub fn is_remote_frame(&self) -> bool {
+        self.id.rtr()
+    }
+
+    /// Returns `true` if this is a data frame.
+    pub fn is_data_frame(&self) -> bool {
+        !self.is_remote_frame()
+    }
+
+    /// Returns the data length code (DLC) of this frame.
+    pub fn dlc(&self) -> usize {
+        self.id.dlc() as usize
+    }
+
+    /// Returns the data held by this frame.
+    ///
+    /// This is always empty for remote frames.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len as usize]
+    }
+}
+
+impl embedded_can::Frame for Frame {
+    fn new(id: impl Into<embedded_can::Id>, data: &[u8]) -> Option<Self> {
+        Self::new_data(Id::from(id.into()), data)
+    }
+
+    fn new_remote(id: impl Into<embedded_can::Id>, dlc: usize) -> Option<Self> {
+        Self::new_remote(Id::from(id.into()), dlc)
+    }
+
+    fn is_extended(&self) -> bool {
+        Frame::is_extended(self)
+    }
+
+    fn is_remote_frame(&self) -> bool {
+        Frame::is_remote_frame(self)
+    }
+
+    fn id(&self) -> embedded_can::Id {
+        Frame::id(self).into()
+    }
+
+    fn dlc(&self) -> usize {
+        Frame::dlc(self)
+    }
+
+    fn data(&self) -> &[u8] {
+        Frame::data(self)
+    }
+}
@@ -1,5 +1,10 @@
 use core::cmp::{Ord, Ordering};
 
+/// Bit offset of a standard (11-bit) identifier within a FlexCAN mailbox ID
+/// register. Shared with the filter subsystem, which programs the same
+/// field in the RXIMR mask registers.
+pub(crate) const STANDARD_ID_SHIFT: u32 = 18;
+
 /// Identifier of a CAN message.
 ///
 /// Can be either a standard identifier (11bit, Range: 0..0x3FF) or a
@@ -28,12 +33,18 @@ impl IdReg {
     const RTR_MASK: u32 = 0b1_u32 << Self::RTR_SHIFT;
 
     const DLC_SHIFT: u32 = 16;
-    const DLC_MASK: u32 = 0b111_u32 << Self::DLC_SHIFT;
+    const DLC_MASK: u32 = 0b1111_u32 << Self::DLC_SHIFT;
+
+    const FDF_SHIFT: u32 = 31;
+    const FDF_MASK: u32 = 0b1_u32 << Self::FDF_SHIFT;
+
+    const BRS_SHIFT: u32 = 30;
+    const BRS_MASK: u32 = 0b1_u32 << Self::BRS_SHIFT;
 
     const TIMESTAMP_SHIFT: u32 = 0;
     const TIMESTAMP_MASK: u32 = 0xFFFF_u32 << Self::TIMESTAMP_SHIFT;
 
-    const STANDARD_SHIFT: u32 = 18;
+    const STANDARD_SHIFT: u32 = STANDARD_ID_SHIFT;
     const EXTENDED_SHIFT: u32 = 0;
 
     /// Creates a new standard identifier (11bit, Range: 0..0x7FF)
@@ -101,6 +112,93 @@ impl IdReg {
     pub fn rtr(self) -> bool {
         self.code & Self::RTR_MASK != 0
     }
+
+    /// Sets the raw data length code (DLC), the 4-bit wire value from which
+    /// the real data length is derived. See [`dlc_to_len`] and
+    /// [`len_to_dlc`] to convert between the wire value and a byte count.
+    #[must_use = "returns a new IdReg without modifying `self`"]
+    pub fn with_dlc(self, dlc: u8) -> IdReg {
+        let dlc = u32::from(dlc) << Self::DLC_SHIFT;
+        Self::new((self.code & !Self::DLC_MASK) | (dlc & Self::DLC_MASK), self.id)
+    }
+
+    /// Returns the raw data length code (DLC), the 4-bit wire value from
+    /// which the real data length is derived. See [`dlc_to_len`] and
+    /// [`len_to_dlc`] to convert between the wire value and a byte count.
+    pub fn dlc(self) -> u8 {
+        ((self.code & Self::DLC_MASK) >> Self::DLC_SHIFT) as u8
+    }
+
+    /// Sets the FD format (FDF) flag. This marks the identifier as belonging
+    /// to a CAN FD frame rather than a classic CAN frame.
+    #[must_use = "returns a new IdReg without modifying `self`"]
+    pub fn with_fdf(self, fdf: bool) -> IdReg {
+        if fdf {
+            Self::new(self.code | Self::FDF_MASK, self.id)
+        } else {
+            Self::new(self.code & !Self::FDF_MASK, self.id)
+        }
+    }
+
+    /// Returns `true` if the identifier belongs to a CAN FD frame (FDF bit
+    /// set).
+    pub fn fdf(self) -> bool {
+        self.code & Self::FDF_MASK != 0
+    }
+
+    /// Sets the bit-rate switch (BRS) flag. This marks a CAN FD frame as
+    /// switching to a higher bit rate for its data phase.
+    #[must_use = "returns a new IdReg without modifying `self`"]
+    pub fn with_brs(self, brs: bool) -> IdReg {
+        if brs {
+            Self::new(self.code | Self::BRS_MASK, self.id)
+        } else {
+            Self::new(self.code & !Self::BRS_MASK, self.id)
+        }
+    }
+
+    /// Returns `true` if a CAN FD frame switches to a higher bit rate for its
+    /// data phase (BRS bit set).
+    pub fn brs(self) -> bool {
+        self.code & Self::BRS_MASK != 0
+    }
+}
+
+/// Converts a raw 4-bit data length code (DLC) to the number of data bytes
+/// it represents.
+///
+/// DLC values `0..=8` map to themselves. The remaining CAN FD steps
+/// (`9..=15`) map to the extended lengths `12, 16, 20, 24, 32, 48, 64`.
+pub const fn dlc_to_len(dlc: u8) -> usize {
+    match dlc {
+        0..=8 => dlc as usize,
+        9 => 12,
+        10 => 16,
+        11 => 20,
+        12 => 24,
+        13 => 32,
+        14 => 48,
+        _ => 64,
+    }
+}
+
+/// Converts a data length in bytes to the raw 4-bit data length code (DLC)
+/// that represents it.
+///
+/// Returns `None` if `len` is not one of the valid classic (`0..=8`) or CAN
+/// FD (`12, 16, 20, 24, 32, 48, 64`) steps.
+pub const fn len_to_dlc(len: usize) -> Option<u8> {
+    match len {
+        0..=8 => Some(len as u8),
+        12 => Some(9),
+        16 => Some(10),
+        20 => Some(11),
+        24 => Some(12),
+        32 => Some(13),
+        48 => Some(14),
+        64 => Some(15),
+        _ => None,
+    }
 }
 
 /// `IdReg` is ordered by priority.
@@ -141,22 +239,29 @@ impl PartialOrd for IdReg {
 }
 
 /// Standard 11-bit CAN Identifier (`0..=0x7FF`).
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct StandardId(u16);
 
 impl StandardId {
     /// CAN ID `0`, the highest priority.
     pub const ZERO: Self = Self(0);
 
+    /// CAN ID `0`, the lowest raw value. Identical to [`Self::ZERO`]; named
+    /// to pair with [`Self::MAX_RAW`] when building ranges.
+    pub const MIN: Self = Self::ZERO;
+
     /// CAN ID `0x7FF`, the lowest priority.
     pub const MAX: Self = Self(0x7FF);
 
+    /// The largest raw value a `StandardId` can hold (`0x7FF`).
+    pub const MAX_RAW: u16 = 0x7FF;
+
     /// Tries to create a `StandardId` from a raw 16-bit integer.
     ///
     /// This will return `None` if `raw` is out of range of an 11-bit integer (`> 0x7FF`).
     #[inline]
     pub const fn new(raw: u16) -> Option<Self> {
-        if raw <= 0x7FF {
+        if raw <= Self::MAX_RAW {
             Some(Self(raw))
         } else {
             None
@@ -182,22 +287,29 @@ impl StandardId {
 }
 
 /// Extended 29-bit CAN Identifier (`0..=1FFF_FFFF`).
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub struct ExtendedId(u32);
 
 impl ExtendedId {
     /// CAN ID `0`, the highest priority.
     pub const ZERO: Self = Self(0);
 
+    /// CAN ID `0`, the lowest raw value. Identical to [`Self::ZERO`]; named
+    /// to pair with [`Self::MAX_RAW`] when building ranges.
+    pub const MIN: Self = Self::ZERO;
+
     /// CAN ID `0x1FFFFFFF`, the lowest priority.
     pub const MAX: Self = Self(0x1FFF_FFFF);
 
+    /// The largest raw value an `ExtendedId` can hold (`0x1FFF_FFFF`).
+    pub const MAX_RAW: u32 = 0x1FFF_FFFF;
+
     /// Tries to create a `ExtendedId` from a raw 32-bit integer.
     ///
     /// This will return `None` if `raw` is out of range of an 29-bit integer (`> 0x1FFF_FFFF`).
     #[inline]
     pub const fn new(raw: u32) -> Option<Self> {
-        if raw <= 0x1FFF_FFFF {
+        if raw <= Self::MAX_RAW {
             Some(Self(raw))
         } else {
             None
@@ -229,7 +341,7 @@ impl ExtendedId {
 }
 
 /// A CAN Identifier (standard or extended).
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub enum Id {
     /// Standard 11-bit Identifier (`0..=0x7FF`).
     Standard(StandardId),
@@ -266,3 +378,181 @@ impl Id {
         }
     }
 }
+
+/// Error returned when a raw integer fits neither a [`StandardId`] nor an
+/// [`ExtendedId`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct IdFromIntError(());
+
+impl TryFrom<u32> for Id {
+    type Error = IdFromIntError;
+
+    /// Picks a [`StandardId`] if `raw` fits in 11 bits, otherwise an
+    /// [`ExtendedId`] if it fits in 29 bits.
+    fn try_from(raw: u32) -> Result<Self, Self::Error> {
+        if raw <= u32::from(StandardId::MAX_RAW) {
+            Ok(Id::Standard(StandardId::new(raw as u16).unwrap()))
+        } else if raw <= ExtendedId::MAX_RAW {
+            Ok(Id::Extended(ExtendedId::new(raw).unwrap()))
+        } else {
+            Err(IdFromIntError(()))
+        }
+    }
+}
+
+impl TryFrom<u16> for Id {
+    type Error = IdFromIntError;
+
+    /// Picks a [`StandardId`] if `raw` fits in 11 bits, otherwise an
+    /// [`ExtendedId`] (every `u16` fits in 29 bits, so this never fails).
+    fn try_from(raw: u16) -> Result<Self, Self::Error> {
+        if raw <= StandardId::MAX_RAW {
+            Ok(Id::Standard(StandardId::new(raw).unwrap()))
+        } else {
+            Ok(Id::Extended(ExtendedId::new(u32::from(raw)).unwrap()))
+        }
+    }
+}
+
+// `embedded-can` defines the same identifier shapes we do. These conversions
+// let protocol stacks built against `embedded_can::Id` run against this HAL
+// without modification.
+
+impl From<StandardId> for embedded_can::StandardId {
+    #[inline]
+    fn from(id: StandardId) -> Self {
+        // Both types cover the same 11-bit range, so this can never fail.
+        embedded_can::StandardId::new(id.as_raw()).unwrap()
+    }
+}
+
+impl From<embedded_can::StandardId> for StandardId {
+    #[inline]
+    fn from(id: embedded_can::StandardId) -> Self {
+        // Both types cover the same 11-bit range, so this can never fail.
+        StandardId::new(id.as_raw()).unwrap()
+    }
+}
+
+impl From<ExtendedId> for embedded_can::ExtendedId {
+    #[inline]
+    fn from(id: ExtendedId) -> Self {
+        // Both types cover the same 29-bit range, so this can never fail.
+        embedded_can::ExtendedId::new(id.as_raw()).unwrap()
+    }
+}
+
+impl From<embedded_can::ExtendedId> for ExtendedId {
+    #[inline]
+    fn from(id: embedded_can::ExtendedId) -> Self {
+        // Both types cover the same 29-bit range, so this can never fail.
+        ExtendedId::new(id.as_raw()).unwrap()
+    }
+}
+
+impl From<Id> for embedded_can::Id {
+    fn from(id: Id) -> Self {
+        match id {
+            Id::Standard(id) => embedded_can::Id::Standard(id.into()),
+            Id::Extended(id) => embedded_can::Id::Extended(id.into()),
+        }
+    }
+}
+
+impl From<embedded_can::Id> for Id {
+    fn from(id: embedded_can::Id) -> Self {
+        match id {
+            embedded_can::Id::Standard(id) => Id::Standard(id.into()),
+            embedded_can::Id::Extended(id) => Id::Extended(id.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dlc_to_len_covers_classic_range() {
+        for dlc in 0..=8u8 {
+            assert_eq!(dlc_to_len(dlc), dlc as usize);
+        }
+    }
+
+    #[test]
+    fn dlc_to_len_covers_fd_steps() {
+        assert_eq!(dlc_to_len(9), 12);
+        assert_eq!(dlc_to_len(10), 16);
+        assert_eq!(dlc_to_len(11), 20);
+        assert_eq!(dlc_to_len(12), 24);
+        assert_eq!(dlc_to_len(13), 32);
+        assert_eq!(dlc_to_len(14), 48);
+        assert_eq!(dlc_to_len(15), 64);
+    }
+
+    #[test]
+    fn len_to_dlc_accepts_valid_steps() {
+        for len in 0..=8usize {
+            assert_eq!(len_to_dlc(len), Some(len as u8));
+        }
+        assert_eq!(len_to_dlc(12), Some(9));
+        assert_eq!(len_to_dlc(16), Some(10));
+        assert_eq!(len_to_dlc(20), Some(11));
+        assert_eq!(len_to_dlc(24), Some(12));
+        assert_eq!(len_to_dlc(32), Some(13));
+        assert_eq!(len_to_dlc(48), Some(14));
+        assert_eq!(len_to_dlc(64), Some(15));
+    }
+
+    #[test]
+    fn len_to_dlc_rejects_non_step_lengths() {
+        assert_eq!(len_to_dlc(9), None);
+        assert_eq!(len_to_dlc(15), None);
+        assert_eq!(len_to_dlc(65), None);
+    }
+
+    #[test]
+    fn id_try_from_u32_picks_standard_up_to_max() {
+        assert_eq!(
+            Id::try_from(0x7FF_u32).unwrap(),
+            Id::Standard(StandardId::new(0x7FF).unwrap())
+        );
+    }
+
+    #[test]
+    fn id_try_from_u32_picks_extended_above_standard_max() {
+        assert_eq!(
+            Id::try_from(0x800_u32).unwrap(),
+            Id::Extended(ExtendedId::new(0x800).unwrap())
+        );
+        assert_eq!(
+            Id::try_from(ExtendedId::MAX_RAW).unwrap(),
+            Id::Extended(ExtendedId::MAX)
+        );
+    }
+
+    #[test]
+    fn id_try_from_u32_rejects_out_of_range() {
+        assert!(Id::try_from(ExtendedId::MAX_RAW + 1).is_err());
+    }
+
+    #[test]
+    fn id_try_from_u16_picks_standard_up_to_max() {
+        assert_eq!(
+            Id::try_from(0x7FF_u16).unwrap(),
+            Id::Standard(StandardId::new(0x7FF).unwrap())
+        );
+    }
+
+    #[test]
+    fn id_try_from_u16_picks_extended_above_standard_max() {
+        assert_eq!(
+            Id::try_from(0x800_u16).unwrap(),
+            Id::Extended(ExtendedId::new(0x800).unwrap())
+        );
+        assert_eq!(
+            Id::try_from(u16::MAX).unwrap(),
+            Id::Extended(ExtendedId::new(u32::from(u16::MAX)).unwrap())
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! CAN identifiers and FlexCAN frame representation.
+
+mod fd;
+mod filter;
+mod frame;
+mod id;
+
+pub use fd::FdFrame;
+pub use filter::{install_filters, Filter, FilterAction, FilterRegisters, TooManyFilters};
+pub use frame::Frame;
+pub use id::{dlc_to_len, len_to_dlc, ExtendedId, Id, IdReg, StandardId};